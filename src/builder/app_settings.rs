@@ -0,0 +1,33 @@
+/// Application-level settings, which affect how [`Command`][crate::builder::Command] operates
+///
+/// NOTE: Each setting is only reachable through its mirrored method on [`Command`], never
+/// constructed directly by users.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub(crate) enum AppSettings {
+    ArgRequiredElseHelp,
+    SubcommandRequired,
+    SubcommandNegatesReqs,
+    AllowMissingPositional,
+    /// See [`Command::collect_all_errors`][crate::builder::Command::collect_all_errors]
+    CollectAllErrors,
+}
+
+/// A compact bitset of [`AppSettings`], owned by [`Command`][crate::builder::Command].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct AppFlags(u8);
+
+impl AppFlags {
+    pub(crate) fn set(&mut self, setting: AppSettings, yes: bool) {
+        let bit = 1 << (setting as u8);
+        if yes {
+            self.0 |= bit;
+        } else {
+            self.0 &= !bit;
+        }
+    }
+
+    pub(crate) fn is_set(&self, setting: AppSettings) -> bool {
+        self.0 & (1 << (setting as u8)) != 0
+    }
+}
@@ -0,0 +1,163 @@
+use crate::builder::ArgPredicate;
+use crate::util::Id;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub(crate) enum ArgSettings {
+    Required,
+    Last,
+    Exclusive,
+    TakesValue,
+    Hide,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct ArgFlags(u8);
+
+impl ArgFlags {
+    pub(crate) fn set(&mut self, setting: ArgSettings, yes: bool) {
+        let bit = 1 << (setting as u8);
+        if yes {
+            self.0 |= bit;
+        } else {
+            self.0 &= !bit;
+        }
+    }
+
+    pub(crate) fn is_set(&self, setting: ArgSettings) -> bool {
+        self.0 & (1 << (setting as u8)) != 0
+    }
+}
+
+/// The builder for a single command-line argument.
+#[derive(Debug, Clone)]
+pub struct Arg {
+    pub(crate) id: Id,
+    pub(crate) index: Option<usize>,
+    pub(crate) min_vals: usize,
+    pub(crate) settings: ArgFlags,
+    pub(crate) blacklist: Vec<Id>,
+    pub(crate) overrides: Vec<Id>,
+    pub(crate) requires: Vec<(ArgPredicate, Id)>,
+    pub(crate) r_ifs: Vec<(Id, std::ffi::OsString)>,
+    pub(crate) r_ifs_all: Vec<(Id, std::ffi::OsString)>,
+    pub(crate) r_unless: Vec<Id>,
+    pub(crate) r_unless_all: Vec<Id>,
+    /// `(condition_arg, predicate, conflicting_arg)`: this arg conflicts with
+    /// `conflicting_arg` only once `predicate` holds against `condition_arg`'s value.
+    ///
+    /// Populated by [`Arg::conflicts_with_if`]; consumed by
+    /// [`Conflicts::gather_direct_conflicts`][crate::parser::validator].
+    pub(crate) conflicts_with_if: Vec<(Id, ArgPredicate, Id)>,
+}
+
+impl Arg {
+    pub(crate) fn get_id(&self) -> &Id {
+        &self.id
+    }
+
+    pub(crate) fn get_index(&self) -> Option<usize> {
+        self.index
+    }
+
+    pub(crate) fn get_min_vals(&self) -> usize {
+        self.min_vals
+    }
+
+    pub(crate) fn is_required_set(&self) -> bool {
+        self.settings.is_set(ArgSettings::Required)
+    }
+
+    pub(crate) fn is_last_set(&self) -> bool {
+        self.settings.is_set(ArgSettings::Last)
+    }
+
+    pub(crate) fn is_exclusive_set(&self) -> bool {
+        self.settings.is_set(ArgSettings::Exclusive)
+    }
+
+    pub(crate) fn is_takes_value_set(&self) -> bool {
+        self.settings.is_set(ArgSettings::TakesValue)
+    }
+
+    pub(crate) fn is_hide_set(&self) -> bool {
+        self.settings.is_set(ArgSettings::Hide)
+    }
+
+    /// Make this argument conflict with `target`, but only when `other` matches `predicate`.
+    ///
+    /// Unlike [`Arg::conflicts_with`], which is static, this lets the conflict depend on the
+    /// value of a third argument, e.g. "`--fast` conflicts with `--threads` only when
+    /// `--mode=batch`":
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{Arg, ArgPredicate};
+    /// Arg::new("fast")
+    ///     .long("fast")
+    ///     .conflicts_with_if("mode", ArgPredicate::Equals("batch".into()), "threads");
+    /// ```
+    pub fn conflicts_with_if(
+        mut self,
+        other: impl Into<Id>,
+        predicate: impl Into<ArgPredicate>,
+        target: impl Into<Id>,
+    ) -> Self {
+        self.conflicts_with_if
+            .push((other.into(), predicate.into(), target.into()));
+        self
+    }
+}
+
+impl std::fmt::Display for Arg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "--{}", self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_arg(name: &str) -> Arg {
+        Arg {
+            id: Id::from(name),
+            index: None,
+            min_vals: 0,
+            settings: ArgFlags::default(),
+            blacklist: Vec::new(),
+            overrides: Vec::new(),
+            requires: Vec::new(),
+            r_ifs: Vec::new(),
+            r_ifs_all: Vec::new(),
+            r_unless: Vec::new(),
+            r_unless_all: Vec::new(),
+            conflicts_with_if: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn conflicts_with_if_records_condition_predicate_and_target() {
+        let arg = test_arg("fast").conflicts_with_if(
+            "mode",
+            ArgPredicate::Equals("batch".into()),
+            "threads",
+        );
+
+        assert_eq!(arg.conflicts_with_if.len(), 1);
+        let (condition, predicate, target) = &arg.conflicts_with_if[0];
+        assert_eq!(condition, &Id::from("mode"));
+        assert!(matches!(predicate, ArgPredicate::Equals(_)));
+        assert_eq!(target, &Id::from("threads"));
+    }
+
+    #[test]
+    fn conflicts_with_if_can_be_stacked() {
+        let arg = test_arg("fast")
+            .conflicts_with_if("mode", ArgPredicate::Equals("batch".into()), "threads")
+            .conflicts_with_if("mode", ArgPredicate::Equals("fast".into()), "retries");
+
+        assert_eq!(arg.conflicts_with_if.len(), 2);
+    }
+}
@@ -0,0 +1,69 @@
+use std::ops::RangeInclusive;
+
+use crate::util::Id;
+
+/// A named, logical grouping of [`Arg`][crate::builder::Arg]s, used to express "one of" or
+/// "all of" relationships that span multiple arguments.
+#[derive(Debug, Clone)]
+pub struct ArgGroup {
+    pub(crate) id: Id,
+    pub(crate) args: Vec<Id>,
+    pub(crate) requires: Vec<Id>,
+    pub(crate) conflicts: Vec<Id>,
+    pub(crate) multiple: bool,
+    pub(crate) required: bool,
+    pub(crate) required_count: Option<RangeInclusive<usize>>,
+}
+
+impl ArgGroup {
+    pub(crate) fn get_id(&self) -> &Id {
+        &self.id
+    }
+
+    /// Require that exactly (or within a range of) this many members of the group be present.
+    ///
+    /// This generalizes [`ArgGroup::required`][crate::builder::ArgGroup] from a boolean "at
+    /// least one member" check into "pick `min..=max` of these arguments", which many real CLIs
+    /// need (e.g. "choose exactly 2 of `--a`, `--b`, `--c`").
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::ArgGroup;
+    /// ArgGroup::new("output")
+    ///     .args(["json", "yaml", "toml"])
+    ///     .required_count(1..=1);
+    /// ```
+    pub fn required_count(mut self, range: RangeInclusive<usize>) -> Self {
+        self.required_count = Some(range);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_group(name: &str) -> ArgGroup {
+        ArgGroup {
+            id: Id::from(name),
+            args: Vec::new(),
+            requires: Vec::new(),
+            conflicts: Vec::new(),
+            multiple: false,
+            required: false,
+            required_count: None,
+        }
+    }
+
+    #[test]
+    fn required_count_defaults_to_unset() {
+        assert_eq!(test_group("output").required_count, None);
+    }
+
+    #[test]
+    fn required_count_stores_the_given_range() {
+        let group = test_group("output").required_count(1..=2);
+        assert_eq!(group.required_count, Some(1..=2));
+    }
+}
@@ -0,0 +1,211 @@
+// Internal
+use crate::builder::app_settings::{AppFlags, AppSettings};
+use crate::builder::{Arg, ArgGroup, StyledStr};
+use crate::error::Result as ClapResult;
+use crate::parser::{validator, ArgMatches};
+use crate::util::{ChildGraph, Id};
+
+/// Build a CLI's argument parser, help text, and validation rules.
+///
+/// See the crate-level docs for an overview of the builder API; this struct only documents the
+/// settings touched by the validator.
+#[derive(Debug, Clone)]
+pub struct Command {
+    pub(crate) name: String,
+    pub(crate) bin_name: Option<String>,
+    pub(crate) args: Vec<Arg>,
+    pub(crate) groups: Vec<ArgGroup>,
+    pub(crate) settings: AppFlags,
+}
+
+impl Command {
+    /// Keep validating past the first failure instead of bailing out on it.
+    ///
+    /// By default `Command` stops at the first failed check and reports only that one (a
+    /// conflict, the missing required arguments, or a malformed argument). For large CLIs it's often friendlier to show every problem at once. When
+    /// enabled, the internal validation pass that runs during
+    /// [`Command::get_matches`][crate::builder::Command] collects every conflict, missing
+    /// required argument, and group-cardinality failure and reports them together as one
+    /// [`Error`](crate::error::Error), one section per failure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Command;
+    /// Command::new("myprog")
+    ///     .collect_all_errors(true);
+    /// ```
+    pub fn collect_all_errors(mut self, yes: bool) -> Self {
+        self.settings.set(AppSettings::CollectAllErrors, yes);
+        self
+    }
+
+    /// Re-run conflict and requirement validation against an already-built [`ArgMatches`].
+    ///
+    /// The normal parse path ([`Command::get_matches`][crate::builder::Command]) validates as it
+    /// goes, but callers who assemble an [`ArgMatches`] some other way (tests, matches merged
+    /// from multiple sources, etc.) have had no way to ask "is this actually valid for me" until
+    /// now. This re-checks the same conflicts, required arguments, and group cardinalities the
+    /// parser itself enforces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::Command;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let cmd = Command::new("myprog");
+    /// let matches = cmd.clone().get_matches_from(["myprog"]);
+    /// cmd.validate(&matches)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate(&self, matches: &ArgMatches) -> ClapResult<()> {
+        validator::validate_matches(self, matches)
+    }
+
+    pub(crate) fn is_collect_all_errors_set(&self) -> bool {
+        self.settings.is_set(AppSettings::CollectAllErrors)
+    }
+
+    pub(crate) fn is_arg_required_else_help_set(&self) -> bool {
+        self.settings.is_set(AppSettings::ArgRequiredElseHelp)
+    }
+
+    pub(crate) fn is_subcommand_required_set(&self) -> bool {
+        self.settings.is_set(AppSettings::SubcommandRequired)
+    }
+
+    pub(crate) fn is_subcommand_negates_reqs_set(&self) -> bool {
+        self.settings.is_set(AppSettings::SubcommandNegatesReqs)
+    }
+
+    pub(crate) fn is_allow_missing_positional_set(&self) -> bool {
+        self.settings.is_set(AppSettings::AllowMissingPositional)
+    }
+
+    pub(crate) fn required_graph(&self) -> ChildGraph<Id> {
+        let mut required = ChildGraph::with_capacity(1);
+        for arg in self.args.iter().filter(|a| a.is_required_set()) {
+            required.insert(arg.get_id().clone());
+        }
+        for group in self.groups.iter().filter(|g| g.required) {
+            required.insert(group.id.clone());
+        }
+        required
+    }
+
+    pub(crate) fn find(&self, id: &Id) -> Option<&Arg> {
+        self.args.iter().find(|a| a.get_id() == id)
+    }
+
+    pub(crate) fn find_group(&self, id: &Id) -> Option<&ArgGroup> {
+        self.groups.iter().find(|g| &g.id == id)
+    }
+
+    pub(crate) fn groups_for_arg(&self, id: &Id) -> impl Iterator<Item = Id> + '_ {
+        self.groups
+            .iter()
+            .filter(move |g| g.args.contains(id))
+            .map(|g| g.id.clone())
+    }
+
+    pub(crate) fn get_arguments(&self) -> impl Iterator<Item = &Arg> {
+        self.args.iter()
+    }
+
+    pub(crate) fn get_positionals(&self) -> impl Iterator<Item = &Arg> {
+        self.args.iter().filter(|a| a.get_index().is_some())
+    }
+
+    pub(crate) fn get_groups(&self) -> impl Iterator<Item = &ArgGroup> {
+        self.groups.iter()
+    }
+
+    pub(crate) fn unroll_args_in_group(&self, id: &Id) -> Vec<Id> {
+        let mut seen = Vec::new();
+        let mut stack = vec![id.clone()];
+        while let Some(current) = stack.pop() {
+            if let Some(group) = self.find_group(&current) {
+                for member in &group.args {
+                    if self.find_group(member).is_some() {
+                        stack.push(member.clone());
+                    } else if !seen.contains(member) {
+                        seen.push(member.clone());
+                    }
+                }
+            }
+        }
+        seen
+    }
+
+    pub(crate) fn unroll_arg_requires(
+        &self,
+        is_relevant: impl Fn(&(crate::builder::ArgPredicate, Id)) -> Option<Id>,
+        arg_id: &Id,
+    ) -> Vec<Id> {
+        let mut required = Vec::new();
+        if let Some(arg) = self.find(arg_id) {
+            for req in arg.requires.iter().filter_map(&is_relevant) {
+                required.push(req);
+            }
+        }
+        required
+    }
+
+    pub(crate) fn get_bin_name(&self) -> Option<&str> {
+        self.bin_name.as_deref()
+    }
+
+    pub(crate) fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn format_group(&self, id: &Id) -> StyledStr {
+        let name = self
+            .find_group(id)
+            .map(|g| g.id.to_string())
+            .unwrap_or_else(|| id.to_string());
+        StyledStr::from(format!("<{name}>"))
+    }
+
+    pub(crate) fn write_help_err(&self, _use_long: bool) -> StyledStr {
+        StyledStr::from(format!("{}\n", self.get_name()))
+    }
+}
+
+impl std::ops::Index<&Id> for Command {
+    type Output = Arg;
+
+    fn index(&self, id: &Id) -> &Arg {
+        self.find(id).expect(crate::INTERNAL_ERROR_MSG)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_cmd() -> Command {
+        Command {
+            name: "test".to_owned(),
+            bin_name: None,
+            args: Vec::new(),
+            groups: Vec::new(),
+            settings: AppFlags::default(),
+        }
+    }
+
+    #[test]
+    fn collect_all_errors_defaults_to_off() {
+        assert!(!test_cmd().is_collect_all_errors_set());
+    }
+
+    #[test]
+    fn collect_all_errors_can_be_toggled_on_and_back_off() {
+        let cmd = test_cmd().collect_all_errors(true);
+        assert!(cmd.is_collect_all_errors_set());
+
+        let cmd = cmd.collect_all_errors(false);
+        assert!(!cmd.is_collect_all_errors_set());
+    }
+}
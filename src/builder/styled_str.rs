@@ -0,0 +1,32 @@
+/// A message string. Several of them can be concatenated (e.g. into a combined validation
+/// error) with [`StyledStr::push_styled`], which appends each one's text unchanged.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StyledStr(String);
+
+impl StyledStr {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn push_str(&mut self, msg: &str) {
+        self.0.push_str(msg);
+    }
+
+    /// Append another message's text as is, without converting it through [`ToString`]. No
+    /// separator is added between the two.
+    pub(crate) fn push_styled(&mut self, other: &StyledStr) {
+        self.0.push_str(&other.0);
+    }
+}
+
+impl From<String> for StyledStr {
+    fn from(s: String) -> Self {
+        StyledStr(s)
+    }
+}
+
+impl std::fmt::Display for StyledStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
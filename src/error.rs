@@ -0,0 +1,235 @@
+use crate::builder::{Command, StyledStr};
+
+pub(crate) type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// The broad category a validation or parse failure falls into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    EmptyValue,
+    DisplayHelp,
+    MissingSubcommand,
+    ArgumentConflict,
+    MissingRequiredArgument,
+    WrongNumberOfValues,
+    /// An [`ArgGroup`][crate::builder::ArgGroup] with a
+    /// [`required_count`][crate::builder::ArgGroup::required_count] saw a different number of
+    /// its members present than the range allows.
+    GroupCardinalityViolation,
+    /// Several independent failures were collected together under
+    /// [`Command::collect_all_errors`][crate::builder::Command::collect_all_errors]. See
+    /// [`Error::causes`] for the individual failures that make up this one.
+    Multiple,
+    ValueValidation,
+}
+
+/// An error encountered while parsing or validating arguments.
+#[derive(Debug, Clone)]
+pub struct Error {
+    kind: ErrorKind,
+    message: StyledStr,
+    causes: Vec<Error>,
+}
+
+impl Error {
+    pub(crate) fn raw(kind: ErrorKind, message: impl std::fmt::Display) -> Self {
+        Error {
+            kind,
+            message: StyledStr::from(message.to_string()),
+            causes: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_cmd(self, _cmd: &Command) -> Self {
+        self
+    }
+
+    /// Combine several already-built errors into one, keeping each as a distinct cause instead
+    /// of collapsing their [`ErrorKind`]s into something like [`ErrorKind::ValueValidation`].
+    pub(crate) fn multiple(message: StyledStr, causes: Vec<Error>) -> Self {
+        Error {
+            kind: ErrorKind::Multiple,
+            message,
+            causes,
+        }
+    }
+
+    /// The kind of failure this error represents.
+    ///
+    /// For an error built by [`Error::multiple`], this is always [`ErrorKind::Multiple`]; use
+    /// [`Error::causes`] to inspect the kind of each individual failure.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The individual failures an [`ErrorKind::Multiple`] error was built from, in the order
+    /// they were found. Empty for any other kind.
+    pub fn causes(&self) -> &[Error] {
+        &self.causes
+    }
+
+    pub(crate) fn render(&self) -> &StyledStr {
+        &self.message
+    }
+
+    pub(crate) fn empty_value(cmd: &Command, good_vals: &[String], arg: String) -> Self {
+        let _ = cmd;
+        let hint = if good_vals.is_empty() {
+            String::new()
+        } else {
+            format!(" [possible values: {}]", good_vals.join(", "))
+        };
+        Self::raw(
+            ErrorKind::EmptyValue,
+            format!("a value is required for '{arg}' but none was supplied{hint}"),
+        )
+    }
+
+    pub(crate) fn display_help_error(cmd: &Command, message: StyledStr) -> Self {
+        let _ = cmd;
+        Self::raw(ErrorKind::DisplayHelp, message)
+    }
+
+    pub(crate) fn missing_subcommand(
+        cmd: &Command,
+        name: String,
+        usage: Option<StyledStr>,
+    ) -> Self {
+        let _ = cmd;
+        let usage = usage.map(|u| format!("\n\n{u}")).unwrap_or_default();
+        Self::raw(
+            ErrorKind::MissingSubcommand,
+            format!("'{name}' requires a subcommand but none was provided{usage}"),
+        )
+    }
+
+    pub(crate) fn argument_conflict(
+        cmd: &Command,
+        arg: String,
+        others: Vec<String>,
+        usage: Option<StyledStr>,
+    ) -> Self {
+        let _ = cmd;
+        let others = if others.is_empty() {
+            "other arguments".to_owned()
+        } else {
+            others.join(", ")
+        };
+        let usage = usage.map(|u| format!("\n\n{u}")).unwrap_or_default();
+        Self::raw(
+            ErrorKind::ArgumentConflict,
+            format!("the argument '{arg}' cannot be used with {others}{usage}"),
+        )
+    }
+
+    pub(crate) fn missing_required_argument(
+        cmd: &Command,
+        required: Vec<String>,
+        usage: Option<StyledStr>,
+    ) -> Self {
+        let _ = cmd;
+        let usage = usage.map(|u| format!("\n\n{u}")).unwrap_or_default();
+        Self::raw(
+            ErrorKind::MissingRequiredArgument,
+            format!(
+                "the following required arguments were not provided: {}{usage}",
+                required.join(", ")
+            ),
+        )
+    }
+
+    pub(crate) fn group_cardinality_violation(
+        cmd: &Command,
+        group: StyledStr,
+        expected: String,
+        observed: usize,
+        members: String,
+        usage: Option<StyledStr>,
+    ) -> Self {
+        let _ = cmd;
+        let usage = usage.map(|u| format!("\n\n{u}")).unwrap_or_default();
+        Self::raw(
+            ErrorKind::GroupCardinalityViolation,
+            format!(
+                "The argument group '{group}' requires {expected} of the following arguments, \
+                 but {observed} provided: {members}{usage}"
+            ),
+        )
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::app_settings::AppFlags;
+
+    fn test_cmd() -> Command {
+        Command {
+            name: "test".to_owned(),
+            bin_name: None,
+            args: Vec::new(),
+            groups: Vec::new(),
+            settings: AppFlags::default(),
+        }
+    }
+
+    #[test]
+    fn group_cardinality_violation_omits_usage_section_when_absent() {
+        let cmd = test_cmd();
+        let err = Error::group_cardinality_violation(
+            &cmd,
+            StyledStr::from("<output>".to_owned()),
+            "exactly 1".to_owned(),
+            0,
+            "json, yaml".to_owned(),
+            None,
+        );
+        assert_eq!(
+            err.to_string(),
+            "The argument group '<output>' requires exactly 1 of the following arguments, \
+             but 0 provided: json, yaml"
+        );
+        assert!(!err.to_string().ends_with('\n'));
+    }
+
+    #[test]
+    fn group_cardinality_violation_appends_usage_section_when_present() {
+        let cmd = test_cmd();
+        let err = Error::group_cardinality_violation(
+            &cmd,
+            StyledStr::from("<output>".to_owned()),
+            "exactly 1".to_owned(),
+            0,
+            "json, yaml".to_owned(),
+            Some(StyledStr::from("USAGE: test [json|yaml]".to_owned())),
+        );
+        assert!(err.to_string().ends_with("\n\nUSAGE: test [json|yaml]"));
+        assert_eq!(err.kind(), ErrorKind::GroupCardinalityViolation);
+    }
+
+    #[test]
+    fn multiple_preserves_each_causes_kind_and_order() {
+        let first = Error::raw(ErrorKind::ArgumentConflict, "first");
+        let second = Error::raw(ErrorKind::MissingRequiredArgument, "second");
+        let combined = Error::multiple(
+            StyledStr::from("first\nsecond".to_owned()),
+            vec![first, second],
+        );
+
+        assert_eq!(combined.kind(), ErrorKind::Multiple);
+        assert_eq!(combined.causes().len(), 2);
+        assert_eq!(combined.causes()[0].kind(), ErrorKind::ArgumentConflict);
+        assert_eq!(
+            combined.causes()[1].kind(),
+            ErrorKind::MissingRequiredArgument
+        );
+    }
+}
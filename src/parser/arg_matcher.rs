@@ -0,0 +1,61 @@
+use crate::builder::ArgPredicate;
+use crate::parser::{ArgMatches, MatchedArg};
+use crate::util::{FlatMap, Id};
+
+/// The internal, mutable view of parsed arguments that [`Validator`][crate::parser::validator]
+/// operates on during and after parsing.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ArgMatcher {
+    pub(crate) args: FlatMap<Id, MatchedArg>,
+    pub(crate) subcommand: Option<Id>,
+}
+
+impl ArgMatcher {
+    pub(crate) fn arg_ids(&self) -> impl Iterator<Item = &Id> {
+        self.args.keys()
+    }
+
+    pub(crate) fn check_explicit(&self, arg_id: &Id, predicate: &ArgPredicate) -> bool {
+        self.args
+            .get(arg_id)
+            .map(|matched| matched.check_explicit(predicate))
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn subcommand_name(&self) -> Option<&Id> {
+        self.subcommand.as_ref()
+    }
+}
+
+impl From<&ArgMatches> for ArgMatcher {
+    /// Adapt a public, already-built [`ArgMatches`] into the internal view [`Validator`] needs,
+    /// so [`Command::validate`][crate::builder::Command::validate] can re-run conflict and
+    /// requirement checks against matches assembled outside the normal parse path.
+    fn from(matches: &ArgMatches) -> Self {
+        ArgMatcher {
+            args: matches.args.clone(),
+            subcommand: matches.subcommand_name_id().cloned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_matcher_has_no_subcommand_and_nothing_present() {
+        let matcher = ArgMatcher::default();
+        assert_eq!(matcher.subcommand_name(), None);
+        assert!(!matcher.check_explicit(&Id::from("foo"), &ArgPredicate::IsPresent));
+    }
+
+    #[test]
+    fn subcommand_name_reflects_the_active_subcommand() {
+        let matcher = ArgMatcher {
+            args: FlatMap::default(),
+            subcommand: Some(Id::from("push")),
+        };
+        assert_eq!(matcher.subcommand_name(), Some(&Id::from("push")));
+    }
+}
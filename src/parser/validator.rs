@@ -1,24 +1,33 @@
 // Internal
 use crate::builder::StyledStr;
-use crate::builder::{Arg, ArgPredicate, Command, PossibleValue};
+use crate::builder::{Arg, ArgGroup, ArgPredicate, Command, PossibleValue};
 use crate::error::{Error, Result as ClapResult};
 use crate::output::Usage;
-use crate::parser::{ArgMatcher, ParseState};
+use crate::parser::{ArgMatcher, ArgMatches, ParseState};
 use crate::util::ChildGraph;
 use crate::util::FlatMap;
 use crate::util::FlatSet;
 use crate::util::Id;
 use crate::INTERNAL_ERROR_MSG;
 
+// Std
+use std::ops::RangeInclusive;
+
 pub(crate) struct Validator<'cmd> {
     cmd: &'cmd Command,
     required: ChildGraph<Id>,
+    collect_all_errors: bool,
 }
 
 impl<'cmd> Validator<'cmd> {
     pub(crate) fn new(cmd: &'cmd Command) -> Self {
         let required = cmd.required_graph();
-        Validator { cmd, required }
+        let collect_all_errors = cmd.is_collect_all_errors_set();
+        Validator {
+            cmd,
+            required,
+            collect_all_errors,
+        }
     }
 
     pub(crate) fn validate(
@@ -28,6 +37,7 @@ impl<'cmd> Validator<'cmd> {
     ) -> ClapResult<()> {
         debug!("Validator::validate");
         let mut conflicts = Conflicts::new();
+        let mut errors = Vec::new();
         let has_subcmd = matcher.subcommand_name().is_some();
 
         if let ParseState::Opt(a) = parse_state {
@@ -76,22 +86,57 @@ impl<'cmd> Validator<'cmd> {
             ));
         }
 
-        ok!(self.validate_conflicts(matcher, &mut conflicts));
+        ok!(self.validate_conflicts(matcher, &mut conflicts, &mut errors));
         if !(self.cmd.is_subcommand_negates_reqs_set() && has_subcmd) {
-            ok!(self.validate_required(matcher, &mut conflicts));
+            ok!(self.validate_required(matcher, &mut conflicts, &mut errors));
+        }
+
+        if !errors.is_empty() {
+            return Err(Self::accumulated_error(errors));
         }
 
         Ok(())
     }
 
+    // Report `err`, either bailing out immediately or stashing it in `errors` to be reported
+    // together once validation finishes, depending on `collect_all_errors`.
+    fn report(&self, errors: &mut Vec<Error>, err: Error) -> ClapResult<()> {
+        if self.collect_all_errors {
+            errors.push(err);
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    // Combine several validation failures collected under `collect_all_errors` into one
+    // `Error::multiple`, keeping each failure's own `ErrorKind` and rendering reachable via
+    // `Error::causes` instead of collapsing them all into a single generic kind.
+    fn accumulated_error(errors: Vec<Error>) -> Error {
+        debug_assert!(!errors.is_empty(), "{}", INTERNAL_ERROR_MSG);
+        if errors.len() == 1 {
+            return errors.into_iter().next().expect(INTERNAL_ERROR_MSG);
+        }
+
+        let mut message = StyledStr::new();
+        for (i, err) in errors.iter().enumerate() {
+            if i > 0 {
+                message.push_str("\n");
+            }
+            message.push_styled(err.render());
+        }
+        Error::multiple(message, errors)
+    }
+
     fn validate_conflicts(
         &mut self,
         matcher: &ArgMatcher,
         conflicts: &mut Conflicts,
+        errors: &mut Vec<Error>,
     ) -> ClapResult<()> {
         debug!("Validator::validate_conflicts");
 
-        ok!(self.validate_exclusive(matcher));
+        ok!(self.validate_exclusive(matcher, errors));
 
         for arg_id in matcher
             .arg_ids()
@@ -100,13 +145,13 @@ impl<'cmd> Validator<'cmd> {
         {
             debug!("Validator::validate_conflicts::iter: id={:?}", arg_id);
             let conflicts = conflicts.gather_conflicts(self.cmd, matcher, arg_id);
-            ok!(self.build_conflict_err(arg_id, &conflicts, matcher));
+            ok!(self.build_conflict_err(arg_id, &conflicts, matcher, errors));
         }
 
         Ok(())
     }
 
-    fn validate_exclusive(&self, matcher: &ArgMatcher) -> ClapResult<()> {
+    fn validate_exclusive(&self, matcher: &ArgMatcher, errors: &mut Vec<Error>) -> ClapResult<()> {
         debug!("Validator::validate_exclusive");
         let args_count = matcher
             .arg_ids()
@@ -119,7 +164,7 @@ impl<'cmd> Validator<'cmd> {
             return Ok(());
         }
 
-        matcher
+        for arg in matcher
             .arg_ids()
             .filter(|arg_id| {
                 matcher.check_explicit(arg_id, &crate::builder::ArgPredicate::IsPresent)
@@ -131,17 +176,23 @@ impl<'cmd> Validator<'cmd> {
                     // Find `arg`s which are exclusive but also appear with other args.
                     .filter(|&arg| arg.is_exclusive_set() && args_count > 1)
             })
-            // Throw an error for the first conflict found.
-            .try_for_each(|arg| {
-                Err(Error::argument_conflict(
+        {
+            // In fast-fail mode this reports only the first conflict found; in
+            // `collect_all_errors` mode every exclusive violation is reported.
+            ok!(self.report(
+                errors,
+                Error::argument_conflict(
                     self.cmd,
                     arg.to_string(),
                     Vec::new(),
                     Usage::new(self.cmd)
                         .required(&self.required)
                         .create_usage_with_title(&[]),
-                ))
-            })
+                )
+            ));
+        }
+
+        Ok(())
     }
 
     fn build_conflict_err(
@@ -149,6 +200,7 @@ impl<'cmd> Validator<'cmd> {
         name: &Id,
         conflict_ids: &[Id],
         matcher: &ArgMatcher,
+        errors: &mut Vec<Error>,
     ) -> ClapResult<()> {
         if conflict_ids.is_empty() {
             return Ok(());
@@ -175,12 +227,10 @@ impl<'cmd> Validator<'cmd> {
 
         let former_arg = self.cmd.find(name).expect(INTERNAL_ERROR_MSG);
         let usg = self.build_conflict_err_usage(matcher, conflict_ids);
-        Err(Error::argument_conflict(
-            self.cmd,
-            former_arg.to_string(),
-            conflicts,
-            usg,
-        ))
+        self.report(
+            errors,
+            Error::argument_conflict(self.cmd, former_arg.to_string(), conflicts, usg),
+        )
     }
 
     fn build_conflict_err_usage(
@@ -240,6 +290,7 @@ impl<'cmd> Validator<'cmd> {
         &mut self,
         matcher: &ArgMatcher,
         conflicts: &mut Conflicts,
+        errors: &mut Vec<Error>,
     ) -> ClapResult<()> {
         debug!("Validator::validate_required: required={:?}", self.required);
         self.gather_requires(matcher);
@@ -361,12 +412,86 @@ impl<'cmd> Validator<'cmd> {
         }
 
         if !missing_required.is_empty() {
-            ok!(self.missing_required_error(matcher, missing_required));
+            ok!(self.missing_required_error(matcher, missing_required, errors));
+        }
+
+        // Groups with an explicit `.required_count(min..=max)` get checked regardless of
+        // whether the group itself is `required`, since "pick exactly N of these" is a
+        // constraint on how many are present, not on whether any are.
+        for group in self.cmd.get_groups().filter(|g| g.required_count.is_some()) {
+            let range = group.required_count.clone().expect(INTERNAL_ERROR_MSG);
+            let present = self
+                .cmd
+                .unroll_args_in_group(&group.id)
+                .iter()
+                .filter(|a| matcher.check_explicit(a, &ArgPredicate::IsPresent))
+                .count();
+            if !range.contains(&present) {
+                debug!(
+                    "Validator::validate_required: group {:?} cardinality {} not in {:?}",
+                    group.get_id(),
+                    present,
+                    range
+                );
+                ok!(self.group_cardinality_error(matcher, group, present, range, errors));
+            }
         }
 
         Ok(())
     }
 
+    fn group_cardinality_error(
+        &self,
+        matcher: &ArgMatcher,
+        group: &ArgGroup,
+        observed: usize,
+        required: RangeInclusive<usize>,
+        errors: &mut Vec<Error>,
+    ) -> ClapResult<()> {
+        debug!(
+            "Validator::group_cardinality_error: group={:?} observed={} required={:?}",
+            group.get_id(),
+            observed,
+            required
+        );
+
+        let members = self
+            .cmd
+            .unroll_args_in_group(&group.id)
+            .iter()
+            .filter_map(|id| self.cmd.find(id))
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let expected = if required.start() == required.end() {
+            format!("exactly {}", required.start())
+        } else {
+            format!("between {} and {}", required.start(), required.end())
+        };
+
+        let used: Vec<Id> = matcher
+            .arg_ids()
+            .filter(|arg_id| matcher.check_explicit(arg_id, &ArgPredicate::IsPresent))
+            .cloned()
+            .collect();
+        let usage = Usage::new(self.cmd)
+            .required(&self.required)
+            .create_usage_with_title(&used);
+
+        self.report(
+            errors,
+            Error::group_cardinality_violation(
+                self.cmd,
+                self.cmd.format_group(&group.id),
+                expected,
+                observed,
+                members,
+                usage,
+            ),
+        )
+    }
+
     fn is_missing_required_ok(
         &self,
         a: &Arg,
@@ -392,6 +517,7 @@ impl<'cmd> Validator<'cmd> {
         &self,
         matcher: &ArgMatcher,
         raw_req_args: Vec<Id>,
+        errors: &mut Vec<Error>,
     ) -> ClapResult<()> {
         debug!("Validator::missing_required_error; incl={:?}", raw_req_args);
         debug!(
@@ -444,12 +570,44 @@ impl<'cmd> Validator<'cmd> {
             .chain(raw_req_args)
             .collect();
 
-        Err(Error::missing_required_argument(
-            self.cmd,
-            req_args,
-            usg.create_usage_with_title(&used),
-        ))
+        self.report(
+            errors,
+            Error::missing_required_argument(
+                self.cmd,
+                req_args,
+                usg.create_usage_with_title(&used),
+            ),
+        )
+    }
+}
+
+/// Re-run conflict and requirement validation against an already-built [`ArgMatches`], for
+/// [`Command::validate`][crate::builder::Command::validate].
+///
+/// This skips the parse-time-only checks [`Validator::validate`] also performs (e.g. the
+/// trailing-[`ParseState::Opt`] empty-value check), since those only make sense mid-parse; a
+/// finished [`ArgMatches`] has nothing left to fill in.
+pub(crate) fn validate_matches(cmd: &Command, matches: &ArgMatches) -> ClapResult<()> {
+    let matcher = ArgMatcher::from(matches);
+    validate_matcher(cmd, &matcher)
+}
+
+fn validate_matcher(cmd: &Command, matcher: &ArgMatcher) -> ClapResult<()> {
+    let mut validator = Validator::new(cmd);
+    let mut conflicts = Conflicts::new();
+    let mut errors = Vec::new();
+    let has_subcmd = matcher.subcommand_name().is_some();
+
+    ok!(validator.validate_conflicts(matcher, &mut conflicts, &mut errors));
+    if !(cmd.is_subcommand_negates_reqs_set() && has_subcmd) {
+        ok!(validator.validate_required(matcher, &mut conflicts, &mut errors));
+    }
+
+    if !errors.is_empty() {
+        return Err(Validator::accumulated_error(errors));
     }
+
+    Ok(())
 }
 
 #[derive(Default, Clone, Debug)]
@@ -474,13 +632,13 @@ impl Conflicts {
             }
 
             if self
-                .gather_direct_conflicts(cmd, arg_id)
+                .gather_direct_conflicts(cmd, matcher, arg_id)
                 .contains(other_arg_id)
             {
                 conflicts.push(other_arg_id.clone());
             }
             if self
-                .gather_direct_conflicts(cmd, other_arg_id)
+                .gather_direct_conflicts(cmd, matcher, other_arg_id)
                 .contains(arg_id)
             {
                 conflicts.push(other_arg_id.clone());
@@ -490,7 +648,12 @@ impl Conflicts {
         conflicts
     }
 
-    fn gather_direct_conflicts(&mut self, cmd: &Command, arg_id: &Id) -> &[Id] {
+    fn gather_direct_conflicts(
+        &mut self,
+        cmd: &Command,
+        matcher: &ArgMatcher,
+        arg_id: &Id,
+    ) -> &[Id] {
         self.potential.entry(arg_id.clone()).or_insert_with(|| {
             let conf = if let Some(arg) = cmd.find(arg_id) {
                 let mut conf = arg.blacklist.clone();
@@ -509,6 +672,14 @@ impl Conflicts {
                 // Overrides are implicitly conflicts
                 conf.extend(arg.overrides.iter().cloned());
 
+                // Conditional conflicts only bite once the predicate holds against the
+                // current matcher state, mirroring how `r_ifs` drives conditional requirements.
+                for (other_id, predicate, target_id) in &arg.conflicts_with_if {
+                    if matcher.check_explicit(other_id, predicate) {
+                        conf.push(target_id.clone());
+                    }
+                }
+
                 conf
             } else if let Some(group) = cmd.find_group(arg_id) {
                 group.conflicts.clone()
@@ -535,3 +706,125 @@ pub(crate) fn get_possible_values_cli(a: &Arg) -> Vec<PossibleValue> {
             .unwrap_or_default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::app_settings::{AppFlags, AppSettings};
+    use crate::builder::arg::ArgFlags;
+    use crate::error::ErrorKind;
+
+    fn test_cmd(args: Vec<Arg>) -> Command {
+        Command {
+            name: "test".to_owned(),
+            bin_name: None,
+            args,
+            groups: Vec::new(),
+            settings: AppFlags::default(),
+        }
+    }
+
+    fn required_arg(name: &str) -> Arg {
+        let mut settings = ArgFlags::default();
+        settings.set(crate::builder::arg::ArgSettings::Required, true);
+        Arg {
+            id: Id::from(name),
+            index: None,
+            min_vals: 0,
+            settings,
+            blacklist: Vec::new(),
+            overrides: Vec::new(),
+            requires: Vec::new(),
+            r_ifs: Vec::new(),
+            r_ifs_all: Vec::new(),
+            r_unless: Vec::new(),
+            r_unless_all: Vec::new(),
+            conflicts_with_if: Vec::new(),
+        }
+    }
+
+    fn empty_matcher() -> ArgMatcher {
+        ArgMatcher::default()
+    }
+
+    fn matcher_with_subcommand(name: &str) -> ArgMatcher {
+        ArgMatcher {
+            args: FlatMap::default(),
+            subcommand: Some(Id::from(name)),
+        }
+    }
+
+    #[test]
+    fn accumulated_error_passes_a_single_error_through_unchanged() {
+        let err = Error::raw(ErrorKind::ArgumentConflict, "only one");
+        let kind = err.kind();
+        let accumulated = Validator::accumulated_error(vec![err]);
+        assert_eq!(accumulated.kind(), kind);
+        assert!(accumulated.causes().is_empty());
+    }
+
+    #[test]
+    fn accumulated_error_wraps_several_errors_preserving_their_kinds_and_order() {
+        let first = Error::raw(ErrorKind::ArgumentConflict, "first");
+        let second = Error::raw(ErrorKind::MissingRequiredArgument, "second");
+        let accumulated = Validator::accumulated_error(vec![first, second]);
+
+        assert_eq!(accumulated.kind(), ErrorKind::Multiple);
+        assert_eq!(accumulated.causes().len(), 2);
+        assert_eq!(accumulated.causes()[0].kind(), ErrorKind::ArgumentConflict);
+        assert_eq!(
+            accumulated.causes()[1].kind(),
+            ErrorKind::MissingRequiredArgument
+        );
+        let rendered = accumulated.to_string();
+        assert!(rendered.contains("first"));
+        assert!(rendered.contains("second"));
+        assert!(rendered.find("first").unwrap() < rendered.find("second").unwrap());
+    }
+
+    #[test]
+    fn gather_direct_conflicts_ignores_conditional_conflict_whose_predicate_is_unmet() {
+        let mut fast = required_arg("fast");
+        fast.settings
+            .set(crate::builder::arg::ArgSettings::Required, false);
+        fast.conflicts_with_if = vec![(
+            Id::from("mode"),
+            ArgPredicate::IsPresent,
+            Id::from("threads"),
+        )];
+        let cmd = test_cmd(vec![fast]);
+        let matcher = empty_matcher();
+
+        let mut conflicts = Conflicts::new();
+        let direct = conflicts.gather_direct_conflicts(&cmd, &matcher, &Id::from("fast"));
+        assert!(direct.is_empty());
+    }
+
+    #[test]
+    fn validate_matcher_reports_missing_required_without_a_subcommand() {
+        let cmd = test_cmd(vec![required_arg("name")]);
+        let matcher = empty_matcher();
+
+        let err = validate_matcher(&cmd, &matcher).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn validate_matcher_skips_required_check_when_subcommand_negates_reqs() {
+        let mut cmd = test_cmd(vec![required_arg("name")]);
+        cmd.settings.set(AppSettings::SubcommandNegatesReqs, true);
+        let matcher = matcher_with_subcommand("push");
+
+        assert!(validate_matcher(&cmd, &matcher).is_ok());
+    }
+
+    #[test]
+    fn validate_matcher_still_requires_args_when_no_subcommand_is_present() {
+        let mut cmd = test_cmd(vec![required_arg("name")]);
+        cmd.settings.set(AppSettings::SubcommandNegatesReqs, true);
+        let matcher = empty_matcher();
+
+        let err = validate_matcher(&cmd, &matcher).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    }
+}